@@ -0,0 +1,23 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rust_concurrecy::matrix::{multiply, multiply_concurrency, multiply_tiled, Matrix};
+
+fn bench_matrix_multiply(c: &mut Criterion) {
+    let size = 512;
+    let a = Matrix::new(vec![1i64; size * size], size, size);
+    let b = Matrix::new(vec![1i64; size * size], size, size);
+
+    let mut group = c.benchmark_group("matrix_multiply_512x512");
+    group.bench_function("multiply", |bencher| {
+        bencher.iter(|| multiply(black_box(&a), black_box(&b)).unwrap())
+    });
+    group.bench_function("multiply_concurrency", |bencher| {
+        bencher.iter(|| multiply_concurrency(black_box(&a), black_box(&b)).unwrap())
+    });
+    group.bench_function("multiply_tiled", |bencher| {
+        bencher.iter(|| multiply_tiled(black_box(&a), black_box(&b), 64).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_matrix_multiply);
+criterion_main!(benches);
@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use rand::Rng;
+use rust_concurrecy::metrics::async_metrics::AsyncMetrics;
+use tokio::time;
+
+// Cheap enough as tokio tasks that we can afford thousands of them, unlike
+// the thread-per-producer version in `metrics.rs`.
+const PRODUCERS: usize = 2000;
+const PAGES: usize = 4;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let metrics = AsyncMetrics::new();
+
+    for idx in 0..PRODUCERS {
+        tokio::spawn(producer(idx, metrics.clone()));
+    }
+
+    for _ in 0..3 {
+        time::sleep(Duration::from_secs(1)).await;
+        println!("{:?}", metrics.snapshot().await?);
+    }
+
+    Ok(())
+}
+
+async fn producer(idx: usize, metrics: AsyncMetrics) -> Result<()> {
+    let page = format!("req.page.{}", idx % PAGES);
+    let sleep_ms = rand::thread_rng().gen_range(100..3000);
+
+    metrics.inc(&page).await?;
+    time::sleep(Duration::from_millis(sleep_ms)).await;
+    metrics.desc(&page).await?;
+    Ok(())
+}
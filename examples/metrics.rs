@@ -2,7 +2,7 @@ use std::{thread, time::Duration};
 
 use anyhow::Result;
 use rand::Rng;
-use rust_concurrecy::Metrics;
+use rust_concurrecy::{Metrics, MetricsStore};
 
 const PROCEDUER: usize = 4;
 const REQUESTER: usize = 3;
@@ -24,7 +24,7 @@ fn main() -> Result<()> {
     }
 }
 
-fn proceduer(idx: usize, metrics: Metrics) -> Result<()> {
+fn proceduer<M: MetricsStore + Clone + Send + 'static>(idx: usize, metrics: M) -> Result<()> {
     thread::spawn(move || {
         loop {
             let mut rng = rand::thread_rng();
@@ -39,7 +39,7 @@ fn proceduer(idx: usize, metrics: Metrics) -> Result<()> {
     Ok(())
 }
 
-fn requester(metrics: Metrics) -> Result<()> {
+fn requester<M: MetricsStore + Clone + Send + 'static>(metrics: M) -> Result<()> {
     thread::spawn(move || {
         loop {
             let mut rng = rand::thread_rng();
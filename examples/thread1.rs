@@ -1,7 +1,7 @@
 use anyhow::{anyhow, Result};
-use std::sync::mpsc::{self, Sender};
-use std::thread;
 use std::time::Duration;
+use tokio::sync::mpsc::{self, Sender};
+use tokio::time;
 
 const NUM_PRODUCES: usize = 4;
 
@@ -12,36 +12,37 @@ struct Msg {
     value: usize,
 }
 
-fn main() -> Result<()> {
-    let (tx, rx) = mpsc::channel();
+#[tokio::main]
+async fn main() -> Result<()> {
+    let (tx, mut rx) = mpsc::channel(32);
 
     for i in 0..NUM_PRODUCES {
         let tx = tx.clone();
-        thread::spawn(move || produce(i, tx));
+        tokio::spawn(produce(i, tx));
     }
 
     drop(tx);
 
-    let consumer = thread::spawn(move || {
-        for msg in rx {
+    let consumer = tokio::spawn(async move {
+        while let Some(msg) = rx.recv().await {
             println!("consumer : {:?}", msg);
         }
         println!("Consumer left");
     });
 
     consumer
-        .join()
-        .map_err(|e| anyhow!("Thread join error: {:?}", e))?;
+        .await
+        .map_err(|e| anyhow!("Task join error: {:?}", e))?;
 
     Ok(())
 }
 
-fn produce(idx: usize, tx: Sender<Msg>) -> Result<()> {
+async fn produce(idx: usize, tx: Sender<Msg>) -> Result<()> {
     loop {
         let value = rand::random::<usize>();
-        tx.send(Msg::new(idx, value))?;
-        thread::sleep(Duration::from_millis(1000));
-        if rand::random::<u8>() % 10 == 0 {
+        tx.send(Msg::new(idx, value)).await?;
+        time::sleep(Duration::from_millis(1000)).await;
+        if rand::random::<u8>().is_multiple_of(10) {
             println!("Producer {:?} left", idx);
             break;
         }
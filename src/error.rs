@@ -0,0 +1,20 @@
+use core::fmt;
+
+/// Minimal error type used in place of `anyhow` on the `no_std` path.
+#[derive(Debug)]
+pub enum Error {
+    DimensionMismatch(&'static str),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::DimensionMismatch(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+pub type Result<T> = core::result::Result<T, Error>;
@@ -0,0 +1,11 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod error;
+pub mod matrix;
+pub mod metrics;
+
+#[cfg(feature = "std")]
+pub use metrics::mutex_metrics::{DashMapMetrics, Metrics, RwLockMetrics};
+#[cfg(feature = "std")]
+pub use metrics::MetricsStore;
+pub use metrics::atomic_metrics::AtomicMetrics;
@@ -1,37 +1,68 @@
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
 use core::fmt;
-use std::{
-    ops::{Add, AddAssign, Mul},
-    sync::mpsc,
-    thread,
-};
+use core::ops::{Add, AddAssign, Mul, Sub};
+
+#[cfg(feature = "std")]
+use std::{sync::mpsc, thread, vec::Vec};
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
 
+#[cfg(feature = "std")]
 use anyhow::{anyhow, Result};
+#[cfg(not(feature = "std"))]
+use crate::error::{Error, Result};
+
+/// Builds the error value for a dimension mismatch, using `anyhow` under `std`
+/// and the crate-local `Error` under `no_std`.
+#[cfg(feature = "std")]
+macro_rules! dim_err {
+    ($msg:expr) => {
+        anyhow!($msg)
+    };
+}
+#[cfg(not(feature = "std"))]
+macro_rules! dim_err {
+    ($msg:expr) => {
+        Error::DimensionMismatch($msg)
+    };
+}
 
+#[cfg(feature = "std")]
 const NUM_THREADS: usize = 4;
 
+// at or below this dimension Strassen's overhead isn't worth it, so fall back to the naive algorithm
+const STRASSEN_THRESHOLD: usize = 64;
+
 pub struct Matrix<T> {
     data: Vec<T>,
     row: usize,
     col: usize,
 }
 
+#[cfg(feature = "std")]
 pub struct MsgInput<T> {
     idx: usize,
     row: Vec<T>,
     col: Vec<T>,
 }
 
+#[cfg(feature = "std")]
 impl<T> MsgInput<T> {
     fn new(idx: usize, row: Vec<T>, col: Vec<T>) -> Self {
         Self { idx, row, col }
     }
 }
 
+#[cfg(feature = "std")]
 pub struct MsgOutput<T> {
     idx: usize,
     data: T,
 }
 
+#[cfg(feature = "std")]
 #[allow(dead_code)]
 impl<T> MsgOutput<T> {
     fn new(idx: usize, data: T) -> Self {
@@ -39,6 +70,7 @@ impl<T> MsgOutput<T> {
     }
 }
 
+#[cfg(feature = "std")]
 pub struct Msg<T> {
     input: MsgInput<T>,
     sender: oneshot::Sender<MsgOutput<T>>,
@@ -49,7 +81,7 @@ where
     T: Default + Add<Output = T> + Mul<Output = T> + AddAssign + Copy,
 {
     if a.len() != b.len() {
-        return Err(anyhow!("a.len must equal to b.len"));
+        return Err(dim_err!("a.len must equal to b.len"));
     }
 
     let mut sum = T::default();
@@ -63,10 +95,10 @@ where
 #[allow(dead_code)]
 pub fn multiply<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>>
 where
-    T: std::fmt::Debug + Add<Output = T> + Mul<Output = T> + AddAssign + Copy + Default,
+    T: fmt::Debug + Add<Output = T> + Mul<Output = T> + AddAssign + Copy + Default,
 {
     if a.col != b.row {
-        return Err(anyhow!("Matrix a.col must equal to b.row"));
+        return Err(dim_err!("Matrix a.col must equal to b.row"));
     }
 
     let mut data = vec![T::default(); a.row * b.col];
@@ -86,6 +118,7 @@ where
     })
 }
 
+#[cfg(feature = "std")]
 #[allow(dead_code)]
 pub fn multiply_concurrency<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>>
 where
@@ -151,7 +184,259 @@ where
     })
 }
 
-impl<T: std::fmt::Debug> Matrix<T> {
+#[cfg(feature = "std")]
+#[allow(dead_code)]
+pub async fn multiply_async<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>>
+where
+    T: std::fmt::Debug
+        + Add<Output = T>
+        + Mul<Output = T>
+        + AddAssign
+        + Copy
+        + Default
+        + Send
+        + 'static,
+{
+    if a.col != b.row {
+        return Err(anyhow!("Matrix a.col must equal to b.row"));
+    }
+
+    let data_length = a.row * b.col;
+    let mut tasks = Vec::with_capacity(data_length);
+    for i in 0..a.row {
+        for j in 0..b.col {
+            let row = a.data[i * a.col..(i + 1) * a.col].to_vec();
+            let col = b.data[j..].iter().step_by(b.col).cloned().collect();
+            tasks.push(tokio::spawn(async move { dot_product(row, col) }));
+        }
+    }
+
+    let mut data = vec![T::default(); data_length];
+    for (idx, result) in futures::future::join_all(tasks).await.into_iter().enumerate() {
+        data[idx] = result.map_err(|e| anyhow!("Task join error: {}", e))??;
+    }
+
+    Ok(Matrix {
+        data,
+        row: a.row,
+        col: b.col,
+    })
+}
+
+#[cfg(feature = "std")]
+fn transpose<T>(m: &Matrix<T>) -> Matrix<T>
+where
+    T: Copy + Default,
+{
+    let mut data = vec![T::default(); m.row * m.col];
+    for i in 0..m.row {
+        for j in 0..m.col {
+            data[j * m.row + i] = m.data[i * m.col + j];
+        }
+    }
+    Matrix {
+        data,
+        row: m.col,
+        col: m.row,
+    }
+}
+
+/// Tiled, rayon-parallel dense multiplication. `b` is transposed up front so each
+/// dot product walks two contiguous slices instead of striding through `b`'s
+/// columns, and the output is split into `block`-row chunks that rayon's
+/// work-stealing pool processes concurrently.
+#[cfg(feature = "std")]
+#[allow(dead_code)]
+pub fn multiply_tiled<T>(a: &Matrix<T>, b: &Matrix<T>, block: usize) -> Result<Matrix<T>>
+where
+    T: std::fmt::Debug + Add<Output = T> + Mul<Output = T> + AddAssign + Copy + Default + Send + Sync,
+{
+    use rayon::prelude::*;
+
+    if a.col != b.row {
+        return Err(anyhow!("Matrix a.col must equal to b.row"));
+    }
+
+    let block = block.max(1);
+    let b_t = transpose(b);
+    let mut data = vec![T::default(); a.row * b.col];
+
+    data.par_chunks_mut(block * b.col).enumerate().for_each(|(chunk_idx, out_chunk)| {
+        let row_start = chunk_idx * block;
+        let rows_in_chunk = out_chunk.len() / b.col;
+        for col_start in (0..b.col).step_by(block) {
+            let col_end = (col_start + block).min(b.col);
+            for r in 0..rows_in_chunk {
+                let a_row = &a.data[(row_start + r) * a.col..(row_start + r + 1) * a.col];
+                for j in col_start..col_end {
+                    let b_col = &b_t.data[j * b_t.col..(j + 1) * b_t.col];
+                    let mut sum = T::default();
+                    for k in 0..a.col {
+                        sum += a_row[k] * b_col[k];
+                    }
+                    out_chunk[r * b.col + j] = sum;
+                }
+            }
+        }
+    });
+
+    Ok(Matrix {
+        data,
+        row: a.row,
+        col: b.col,
+    })
+}
+
+#[allow(dead_code)]
+pub fn add<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>>
+where
+    T: Add<Output = T> + Copy,
+{
+    if a.row != b.row || a.col != b.col {
+        return Err(dim_err!("Matrix dimensions must match for add"));
+    }
+
+    let data = a.data.iter().zip(b.data.iter()).map(|(&x, &y)| x + y).collect();
+    Ok(Matrix {
+        data,
+        row: a.row,
+        col: a.col,
+    })
+}
+
+#[allow(dead_code)]
+pub fn sub<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>>
+where
+    T: Sub<Output = T> + Copy,
+{
+    if a.row != b.row || a.col != b.col {
+        return Err(dim_err!("Matrix dimensions must match for sub"));
+    }
+
+    let data = a.data.iter().zip(b.data.iter()).map(|(&x, &y)| x - y).collect();
+    Ok(Matrix {
+        data,
+        row: a.row,
+        col: a.col,
+    })
+}
+
+fn pad<T>(m: &Matrix<T>, row: usize, col: usize) -> Matrix<T>
+where
+    T: Copy + Default,
+{
+    let mut data = vec![T::default(); row * col];
+    for i in 0..m.row {
+        for j in 0..m.col {
+            data[i * col + j] = m.data[i * m.col + j];
+        }
+    }
+    Matrix { data, row, col }
+}
+
+fn crop<T>(m: &Matrix<T>, row: usize, col: usize) -> Matrix<T>
+where
+    T: Copy,
+{
+    let mut data = Vec::with_capacity(row * col);
+    for i in 0..row {
+        data.extend_from_slice(&m.data[i * m.col..i * m.col + col]);
+    }
+    Matrix { data, row, col }
+}
+
+fn quadrant<T>(m: &Matrix<T>, row_off: usize, col_off: usize, half: usize) -> Matrix<T>
+where
+    T: Copy,
+{
+    let mut data = Vec::with_capacity(half * half);
+    for i in 0..half {
+        let start = (row_off + i) * m.col + col_off;
+        data.extend_from_slice(&m.data[start..start + half]);
+    }
+    Matrix {
+        data,
+        row: half,
+        col: half,
+    }
+}
+
+fn assemble<T>(c11: Matrix<T>, c12: Matrix<T>, c21: Matrix<T>, c22: Matrix<T>) -> Matrix<T>
+where
+    T: Copy + Default,
+{
+    let half = c11.row;
+    let n = half * 2;
+    let mut data = vec![T::default(); n * n];
+    for i in 0..half {
+        for j in 0..half {
+            data[i * n + j] = c11.data[i * half + j];
+            data[i * n + half + j] = c12.data[i * half + j];
+            data[(half + i) * n + j] = c21.data[i * half + j];
+            data[(half + i) * n + half + j] = c22.data[i * half + j];
+        }
+    }
+    Matrix { data, row: n, col: n }
+}
+
+fn strassen_recursive<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>>
+where
+    T: fmt::Debug + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + AddAssign + Copy + Default,
+{
+    let n = a.row;
+    if n <= STRASSEN_THRESHOLD {
+        return multiply(a, b);
+    }
+
+    let half = n / 2;
+    let a11 = quadrant(a, 0, 0, half);
+    let a12 = quadrant(a, 0, half, half);
+    let a21 = quadrant(a, half, 0, half);
+    let a22 = quadrant(a, half, half, half);
+    let b11 = quadrant(b, 0, 0, half);
+    let b12 = quadrant(b, 0, half, half);
+    let b21 = quadrant(b, half, 0, half);
+    let b22 = quadrant(b, half, half, half);
+
+    let m1 = strassen_recursive(&add(&a11, &a22)?, &add(&b11, &b22)?)?;
+    let m2 = strassen_recursive(&add(&a21, &a22)?, &b11)?;
+    let m3 = strassen_recursive(&a11, &sub(&b12, &b22)?)?;
+    let m4 = strassen_recursive(&a22, &sub(&b21, &b11)?)?;
+    let m5 = strassen_recursive(&add(&a11, &a12)?, &b22)?;
+    let m6 = strassen_recursive(&sub(&a21, &a11)?, &add(&b11, &b12)?)?;
+    let m7 = strassen_recursive(&sub(&a12, &a22)?, &add(&b21, &b22)?)?;
+
+    let c11 = add(&sub(&add(&m1, &m4)?, &m5)?, &m7)?;
+    let c12 = add(&m3, &m5)?;
+    let c21 = add(&m2, &m4)?;
+    let c22 = add(&add(&sub(&m1, &m2)?, &m3)?, &m6)?;
+
+    Ok(assemble(c11, c12, c21, c22))
+}
+
+#[allow(dead_code)]
+pub fn multiply_strassen<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>>
+where
+    T: fmt::Debug + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + AddAssign + Copy + Default,
+{
+    if a.col != b.row {
+        return Err(dim_err!("Matrix a.col must equal to b.row"));
+    }
+
+    let dim = a.row.max(a.col).max(b.col);
+    if dim <= STRASSEN_THRESHOLD {
+        return multiply(a, b);
+    }
+
+    let n = dim.next_power_of_two();
+    let a_padded = pad(a, n, n);
+    let b_padded = pad(b, n, n);
+    let c_padded = strassen_recursive(&a_padded, &b_padded)?;
+
+    Ok(crop(&c_padded, a.row, b.col))
+}
+
+impl<T: fmt::Debug> Matrix<T> {
     #[allow(dead_code)]
     // any data type which can by convert to vec
     pub fn new(data: impl Into<Vec<T>>, row: usize, col: usize) -> Self {
@@ -163,7 +448,7 @@ impl<T: std::fmt::Debug> Matrix<T> {
     }
 }
 
-impl<T> std::fmt::Display for Matrix<T>
+impl<T> fmt::Display for Matrix<T>
 where
     T: fmt::Display,
 {
@@ -185,7 +470,7 @@ where
     }
 }
 
-impl<T> std::fmt::Debug for Matrix<T>
+impl<T> fmt::Debug for Matrix<T>
 where
     T: fmt::Display,
 {
@@ -201,6 +486,8 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
 
     #[test]
     fn test_matrix_multiply() -> Result<()> {
@@ -234,30 +521,121 @@ mod tests {
     }
 
     #[test]
-    fn test_multiply_concurrency() -> Result<()> {
+    fn test_multiply_strassen_dimension_mismatch() {
         let a = Matrix::new([1, 2, 3, 4], 2, 2);
-        let b = Matrix::new([1, 2, 3, 4], 2, 2);
-        let c = multiply_concurrency(&a, &b)?;
-        assert_eq!(c.row, 2);
-        assert_eq!(c.col, 2);
-        assert_eq!(c.data, vec![7, 10, 15, 22]);
-        Ok(())
+        let b = Matrix::new([1, 2, 3], 3, 1);
+        assert!(multiply_strassen(&a, &b).is_err());
     }
 
     #[test]
-    fn test_concurrency_time() -> Result<()> {
-        // build large matrix with 100 x 100
-        let a = Matrix::new(vec![1; 100 * 100], 100, 100);
-        let b = Matrix::new(vec![1; 100 * 100], 100, 100);
-        let start = std::time::Instant::now();
-        multiply(&a, &b)?;
-        let duration = start.elapsed();
-        eprintln!("multiply: {:?}", duration);
-
-        let start = std::time::Instant::now();
-        multiply_concurrency(&a, &b)?;
-        let duration = start.elapsed();
-        eprintln!("multiply_concurrency: {:?}", duration);
-        Ok(())
+    fn test_add_dimension_mismatch() {
+        let a = Matrix::new([1, 2, 3, 4], 2, 2);
+        let b = Matrix::new([1, 2, 3], 1, 3);
+        assert!(add(&a, &b).is_err());
+    }
+
+    #[test]
+    fn test_sub_dimension_mismatch() {
+        let a = Matrix::new([1, 2, 3, 4], 2, 2);
+        let b = Matrix::new([1, 2, 3], 1, 3);
+        assert!(sub(&a, &b).is_err());
+    }
+
+    // Everything below needs threads, tokio, rayon or `rand`'s OS-backed
+    // `thread_rng`, none of which are available under `no_std` + `alloc`.
+    #[cfg(feature = "std")]
+    mod std_tests {
+        use super::*;
+
+        #[test]
+        fn test_multiply_concurrency() -> Result<()> {
+            let a = Matrix::new([1, 2, 3, 4], 2, 2);
+            let b = Matrix::new([1, 2, 3, 4], 2, 2);
+            let c = multiply_concurrency(&a, &b)?;
+            assert_eq!(c.row, 2);
+            assert_eq!(c.col, 2);
+            assert_eq!(c.data, vec![7, 10, 15, 22]);
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn test_multiply_async() -> Result<()> {
+            let a = Matrix::new([1, 2, 3, 4], 2, 2);
+            let b = Matrix::new([1, 2, 3, 4], 2, 2);
+            let c = multiply_async(&a, &b).await?;
+            assert_eq!(c.row, 2);
+            assert_eq!(c.col, 2);
+            assert_eq!(c.data, vec![7, 10, 15, 22]);
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn test_multiply_async_dimension_mismatch() {
+            let a = Matrix::new([1, 2, 3, 4], 2, 2);
+            let b = Matrix::new([1, 2, 3], 3, 1);
+            assert!(multiply_async(&a, &b).await.is_err());
+        }
+
+        #[test]
+        fn test_multiply_tiled_matches_naive() -> Result<()> {
+            use rand::Rng;
+
+            let mut rng = rand::thread_rng();
+            let (m, k, n) = (37, 53, 29);
+            let a_data: Vec<i64> = (0..m * k).map(|_| rng.gen_range(-10..10)).collect();
+            let b_data: Vec<i64> = (0..k * n).map(|_| rng.gen_range(-10..10)).collect();
+            let a = Matrix::new(a_data, m, k);
+            let b = Matrix::new(b_data, k, n);
+
+            let expected = multiply(&a, &b)?;
+            let actual = multiply_tiled(&a, &b, 8)?;
+            assert_eq!(actual.row, expected.row);
+            assert_eq!(actual.col, expected.col);
+            assert_eq!(actual.data, expected.data);
+            Ok(())
+        }
+
+        #[test]
+        fn test_multiply_tiled_dimension_mismatch() {
+            let a = Matrix::new([1, 2, 3, 4], 2, 2);
+            let b = Matrix::new([1, 2, 3], 3, 1);
+            assert!(multiply_tiled(&a, &b, 8).is_err());
+        }
+
+        #[test]
+        fn test_multiply_strassen_matches_naive() -> Result<()> {
+            use rand::Rng;
+
+            let mut rng = rand::thread_rng();
+            let (m, k, n) = (70, 90, 65);
+            let a_data: Vec<i64> = (0..m * k).map(|_| rng.gen_range(-10..10)).collect();
+            let b_data: Vec<i64> = (0..k * n).map(|_| rng.gen_range(-10..10)).collect();
+            let a = Matrix::new(a_data, m, k);
+            let b = Matrix::new(b_data, k, n);
+
+            let expected = multiply(&a, &b)?;
+            let actual = multiply_strassen(&a, &b)?;
+            assert_eq!(actual.row, expected.row);
+            assert_eq!(actual.col, expected.col);
+            assert_eq!(actual.data, expected.data);
+            Ok(())
+        }
+
+        #[test]
+        fn test_concurrency_time() -> Result<()> {
+            // build large matrix with 100 x 100
+            let a = Matrix::new(vec![1; 100 * 100], 100, 100);
+            let b = Matrix::new(vec![1; 100 * 100], 100, 100);
+            let start = std::time::Instant::now();
+            multiply(&a, &b)?;
+            let duration = start.elapsed();
+            eprintln!("multiply: {:?}", duration);
+
+            let start = std::time::Instant::now();
+            multiply_concurrency(&a, &b)?;
+            let duration = start.elapsed();
+            eprintln!("multiply_concurrency: {:?}", duration);
+            Ok(())
+        }
     }
 }
@@ -0,0 +1,95 @@
+use std::{collections::HashMap, sync::Arc};
+
+use anyhow::Result;
+use tokio::sync::RwLock;
+
+#[derive(Clone)]
+pub struct AsyncMetrics {
+    data: Arc<RwLock<HashMap<String, i64>>>,
+}
+
+impl AsyncMetrics {
+    pub fn new() -> Self {
+        Self {
+            data: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    pub async fn inc(&self, key: impl Into<String>) -> Result<()> {
+        let mut data = self.data.write().await;
+        let counter = data.entry(key.into()).or_insert(0);
+        *counter += 1;
+        Ok(())
+    }
+
+    pub async fn desc(&self, key: impl Into<String>) -> Result<()> {
+        let mut data = self.data.write().await;
+        let counter = data.entry(key.into()).or_insert(0);
+        *counter -= 1;
+        Ok(())
+    }
+
+    pub async fn add(&self, key: impl Into<String>, delta: i64) -> Result<()> {
+        let mut data = self.data.write().await;
+        let counter = data.entry(key.into()).or_insert(0);
+        *counter += delta;
+        Ok(())
+    }
+
+    pub async fn snapshot(&self) -> Result<HashMap<String, i64>> {
+        let data = self.data.read().await;
+        Ok(data.clone())
+    }
+}
+
+impl Default for AsyncMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_metrics_new() {
+        let metrics = AsyncMetrics::new();
+        assert_eq!(metrics.snapshot().await.unwrap().len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_inc() {
+        let metrics = AsyncMetrics::new();
+        metrics.inc("key1").await.unwrap();
+        metrics.inc("key1").await.unwrap();
+        metrics.inc("key2").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_metrics_desc() {
+        let metrics = AsyncMetrics::new();
+        metrics.inc("key1").await.unwrap();
+        metrics.desc("key1").await.unwrap();
+        assert_eq!(metrics.snapshot().await.unwrap().get("key1"), Some(&0));
+    }
+
+    #[tokio::test]
+    async fn test_metrics_add() {
+        let metrics = AsyncMetrics::new();
+        metrics.inc("key1").await.unwrap();
+        metrics.add("key1", 5).await.unwrap();
+        assert_eq!(metrics.snapshot().await.unwrap().get("key1"), Some(&6));
+    }
+
+    #[tokio::test]
+    async fn test_metrics_snapshot() {
+        let metrics = AsyncMetrics::new();
+        metrics.inc("key1").await.unwrap();
+        metrics.inc("key1").await.unwrap();
+        metrics.inc("key2").await.unwrap();
+        let snapshot = metrics.snapshot().await.unwrap();
+        assert_eq!(snapshot.get("key1"), Some(&2));
+        assert_eq!(snapshot.get("key2"), Some(&1));
+    }
+}
@@ -1,13 +1,36 @@
-use std::{
-    collections::HashMap,
-    fmt::Display,
-    sync::{atomic::AtomicI64, Arc},
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+use core::fmt::Display;
+use core::sync::atomic::{AtomicI64, Ordering};
+
+#[cfg(feature = "std")]
+use std::{collections::HashMap, sync::Arc};
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    sync::Arc,
 };
 
+#[cfg(feature = "std")]
 use anyhow::Result;
+#[cfg(not(feature = "std"))]
+use crate::error::Result;
+
+#[cfg(feature = "std")]
+use super::MetricsStore;
+
+// `HashMap` needs `std`'s random-state hasher, so the `no_std` build keys
+// the counter table with a `BTreeMap` instead.
+#[cfg(feature = "std")]
+type KeyMap = HashMap<&'static str, AtomicI64>;
+#[cfg(not(feature = "std"))]
+type KeyMap = BTreeMap<&'static str, AtomicI64>;
 
 pub struct AtomicMetrics {
-    data: Arc<HashMap<&'static str, AtomicI64>>,
+    data: Arc<KeyMap>,
 }
 
 impl Clone for AtomicMetrics {
@@ -29,7 +52,7 @@ impl AtomicMetrics {
     pub fn incr(&self, key: impl AsRef<str>) -> Result<()> {
         let key = key.as_ref();
         if let Some(counter) = self.data.get(key) {
-            counter.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            counter.fetch_add(1, Ordering::Relaxed);
         }
         Ok(())
     }
@@ -37,21 +60,63 @@ impl AtomicMetrics {
     pub fn desc(&self, key: impl AsRef<str>) -> Result<()> {
         let key = key.as_ref();
         if let Some(counter) = self.data.get(key) {
-            counter.fetch_sub(1, std::sync::atomic::Ordering::Relaxed);
+            counter.fetch_sub(1, Ordering::Relaxed);
+        }
+        Ok(())
+    }
+
+    pub fn add(&self, key: impl AsRef<str>, delta: i64) -> Result<()> {
+        let key = key.as_ref();
+        if let Some(counter) = self.data.get(key) {
+            counter.fetch_add(delta, Ordering::Relaxed);
         }
         Ok(())
     }
+
+    #[cfg(feature = "std")]
+    pub fn snapshot(&self) -> Result<HashMap<String, i64>> {
+        Ok(self
+            .data
+            .iter()
+            .map(|(&key, value)| (key.to_string(), value.load(Ordering::Relaxed)))
+            .collect())
+    }
+
+    /// `alloc`-only counterpart of the `std` snapshot above, keyed by a
+    /// `BTreeMap` since `HashMap` needs `std`'s random-state hasher.
+    #[cfg(not(feature = "std"))]
+    pub fn snapshot(&self) -> Result<BTreeMap<String, i64>> {
+        Ok(self
+            .data
+            .iter()
+            .map(|(&key, value)| (key.to_string(), value.load(Ordering::Relaxed)))
+            .collect())
+    }
+}
+
+#[cfg(feature = "std")]
+impl MetricsStore for AtomicMetrics {
+    fn inc(&self, key: impl AsRef<str>) -> Result<()> {
+        self.incr(key)
+    }
+
+    fn desc(&self, key: impl AsRef<str>) -> Result<()> {
+        AtomicMetrics::desc(self, key)
+    }
+
+    fn add(&self, key: impl AsRef<str>, delta: i64) -> Result<()> {
+        AtomicMetrics::add(self, key, delta)
+    }
+
+    fn snapshot(&self) -> Result<HashMap<String, i64>> {
+        AtomicMetrics::snapshot(self)
+    }
 }
 
 impl Display for AtomicMetrics {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         for (key, value) in self.data.iter() {
-            write!(
-                f,
-                "{}: {}",
-                key,
-                value.load(std::sync::atomic::Ordering::Relaxed)
-            )?;
+            write!(f, "{}: {}", key, value.load(Ordering::Relaxed))?;
         }
         Ok(())
     }
@@ -0,0 +1,24 @@
+#[cfg(feature = "std")]
+pub mod async_metrics;
+pub mod atomic_metrics;
+#[cfg(feature = "std")]
+pub mod mutex_metrics;
+
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+#[cfg(feature = "std")]
+use anyhow::Result;
+
+/// Common interface over the different counter backends (`Mutex`, `RwLock`,
+/// `DashMap`, atomic) so a single workload can be written once and run
+/// against any of them. Only available with the `std` feature, since the
+/// `Mutex`/`RwLock`/`DashMap` backends and the `HashMap` snapshot type all
+/// need the standard library.
+#[cfg(feature = "std")]
+pub trait MetricsStore {
+    fn inc(&self, key: impl AsRef<str>) -> Result<()>;
+    fn desc(&self, key: impl AsRef<str>) -> Result<()>;
+    fn add(&self, key: impl AsRef<str>, delta: i64) -> Result<()>;
+    fn snapshot(&self) -> Result<HashMap<String, i64>>;
+}
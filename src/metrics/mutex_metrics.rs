@@ -7,6 +7,8 @@ use std::{
 use anyhow::{anyhow, Result};
 use dashmap::DashMap;
 
+use super::MetricsStore;
+
 #[derive(Debug, Clone)]
 pub struct Metrics {
     data: Arc<Mutex<HashMap<String, i64>>>,
@@ -17,6 +19,7 @@ pub struct RwLockMetrics {
     data: Arc<RwLock<HashMap<String, i64>>>,
 }
 
+#[derive(Clone)]
 pub struct DashMapMetrics {
     data: Arc<DashMap<String, i64>>,
 }
@@ -46,6 +49,13 @@ impl Metrics {
         let data = self.data.lock().map_err(|e| anyhow!(e.to_string()))?;
         Ok(data.clone())
     }
+
+    pub fn add(&self, key: impl Into<String>, delta: i64) -> Result<()> {
+        let mut data = self.data.lock().map_err(|e| anyhow!(e.to_string()))?;
+        let counter = data.entry(key.into()).or_insert(0);
+        *counter += delta;
+        Ok(())
+    }
 }
 
 impl Default for Metrics {
@@ -54,6 +64,24 @@ impl Default for Metrics {
     }
 }
 
+impl MetricsStore for Metrics {
+    fn inc(&self, key: impl AsRef<str>) -> Result<()> {
+        Metrics::inc(self, key.as_ref().to_string())
+    }
+
+    fn desc(&self, key: impl AsRef<str>) -> Result<()> {
+        Metrics::desc(self, key.as_ref().to_string())
+    }
+
+    fn add(&self, key: impl AsRef<str>, delta: i64) -> Result<()> {
+        Metrics::add(self, key.as_ref().to_string(), delta)
+    }
+
+    fn snapshot(&self) -> Result<HashMap<String, i64>> {
+        Metrics::snapshot(self)
+    }
+}
+
 impl RwLockMetrics {
     #[allow(dead_code)]
     pub fn new() -> Self {
@@ -63,7 +91,6 @@ impl RwLockMetrics {
     }
 
     #[allow(dead_code)]
-
     pub fn inc(&self, key: impl Into<String>) -> Result<()> {
         let mut data = self.data.write().map_err(|e| anyhow!(e.to_string()))?;
         let counter = data.entry(key.into()).or_insert(0);
@@ -84,6 +111,38 @@ impl RwLockMetrics {
         let data = self.data.read().map_err(|e| anyhow!(e.to_string()))?;
         Ok(data.clone())
     }
+
+    #[allow(dead_code)]
+    pub fn add(&self, key: impl Into<String>, delta: i64) -> Result<()> {
+        let mut data = self.data.write().map_err(|e| anyhow!(e.to_string()))?;
+        let counter = data.entry(key.into()).or_insert(0);
+        *counter += delta;
+        Ok(())
+    }
+}
+
+impl Default for RwLockMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MetricsStore for RwLockMetrics {
+    fn inc(&self, key: impl AsRef<str>) -> Result<()> {
+        RwLockMetrics::inc(self, key.as_ref().to_string())
+    }
+
+    fn desc(&self, key: impl AsRef<str>) -> Result<()> {
+        RwLockMetrics::desc(self, key.as_ref().to_string())
+    }
+
+    fn add(&self, key: impl AsRef<str>, delta: i64) -> Result<()> {
+        RwLockMetrics::add(self, key.as_ref().to_string(), delta)
+    }
+
+    fn snapshot(&self) -> Result<HashMap<String, i64>> {
+        RwLockMetrics::snapshot(self)
+    }
 }
 
 impl Display for RwLockMetrics {
@@ -101,7 +160,6 @@ impl DashMapMetrics {
         }
     }
     #[allow(dead_code)]
-
     pub fn inc(&self, key: impl Into<String>) -> Result<()> {
         let mut counter = self.data.entry(key.into()).or_insert(0);
         *counter += 1;
@@ -113,6 +171,46 @@ impl DashMapMetrics {
         *counter -= 1;
         Ok(())
     }
+
+    #[allow(dead_code)]
+    pub fn add(&self, key: impl Into<String>, delta: i64) -> Result<()> {
+        let mut counter = self.data.entry(key.into()).or_insert(0);
+        *counter += delta;
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub fn snapshot(&self) -> Result<HashMap<String, i64>> {
+        Ok(self
+            .data
+            .iter()
+            .map(|entry| (entry.key().clone(), *entry.value()))
+            .collect())
+    }
+}
+
+impl Default for DashMapMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MetricsStore for DashMapMetrics {
+    fn inc(&self, key: impl AsRef<str>) -> Result<()> {
+        DashMapMetrics::inc(self, key.as_ref().to_string())
+    }
+
+    fn desc(&self, key: impl AsRef<str>) -> Result<()> {
+        DashMapMetrics::desc(self, key.as_ref().to_string())
+    }
+
+    fn add(&self, key: impl AsRef<str>, delta: i64) -> Result<()> {
+        DashMapMetrics::add(self, key.as_ref().to_string(), delta)
+    }
+
+    fn snapshot(&self) -> Result<HashMap<String, i64>> {
+        DashMapMetrics::snapshot(self)
+    }
 }
 
 impl Display for DashMapMetrics {
@@ -160,6 +258,14 @@ mod test {
         assert_eq!(snapshot.get("key1"), Some(&2));
         assert_eq!(snapshot.get("key2"), Some(&1));
     }
+
+    #[test]
+    fn test_metrics_add() {
+        let metrics = Metrics::new();
+        metrics.inc("key1").unwrap();
+        metrics.add("key1", 5).unwrap();
+        assert_eq!(metrics.snapshot().unwrap().get("key1"), Some(&6));
+    }
 }
 
 #[cfg(test)]
@@ -199,6 +305,14 @@ mod test_rw_lock_metrics {
         assert_eq!(snapshot.get("key2"), Some(&1));
     }
 
+    #[test]
+    fn test_metrics_add() {
+        let metrics = RwLockMetrics::new();
+        metrics.inc("key1").unwrap();
+        metrics.add("key1", 5).unwrap();
+        assert_eq!(metrics.snapshot().unwrap().get("key1"), Some(&6));
+    }
+
     #[test]
     fn test_metrics_display() {
         let metrics = RwLockMetrics::new();
@@ -208,3 +322,77 @@ mod test_rw_lock_metrics {
         println!("{}", metrics);
     }
 }
+
+#[cfg(test)]
+mod test_dash_map_metrics {
+    use super::*;
+
+    #[test]
+    fn test_metrics_new() {
+        let metrics = DashMapMetrics::new();
+        assert_eq!(metrics.snapshot().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_metrics_inc() {
+        let metrics = DashMapMetrics::new();
+        metrics.inc("key1").unwrap();
+        metrics.inc("key1").unwrap();
+        metrics.inc("key2").unwrap();
+    }
+
+    #[test]
+    fn test_metrics_desc() {
+        let metrics = DashMapMetrics::new();
+        metrics.inc("key1").unwrap();
+        metrics.desc("key1").unwrap();
+        assert_eq!(metrics.snapshot().unwrap().get("key1"), Some(&0));
+    }
+
+    #[test]
+    fn test_metrics_snapshot() {
+        let metrics = DashMapMetrics::new();
+        metrics.inc("key1").unwrap();
+        metrics.inc("key1").unwrap();
+        metrics.inc("key2").unwrap();
+        let snapshot = metrics.snapshot().unwrap();
+        assert_eq!(snapshot.get("key1"), Some(&2));
+        assert_eq!(snapshot.get("key2"), Some(&1));
+    }
+
+    #[test]
+    fn test_metrics_add() {
+        let metrics = DashMapMetrics::new();
+        metrics.inc("key1").unwrap();
+        metrics.add("key1", 5).unwrap();
+        assert_eq!(metrics.snapshot().unwrap().get("key1"), Some(&6));
+    }
+
+    #[test]
+    fn test_metrics_display() {
+        let metrics = DashMapMetrics::new();
+        metrics.inc("key1").unwrap();
+        metrics.inc("key1").unwrap();
+        metrics.inc("key2").unwrap();
+        println!("{}", metrics);
+    }
+}
+
+#[cfg(test)]
+mod test_metrics_store {
+    use super::*;
+
+    fn exercise(metrics: impl MetricsStore) {
+        metrics.inc("key1").unwrap();
+        metrics.add("key1", 5).unwrap();
+        metrics.desc("key1").unwrap();
+        assert_eq!(metrics.snapshot().unwrap().get("key1"), Some(&5));
+    }
+
+    #[test]
+    fn test_metrics_store_impls() {
+        exercise(Metrics::new());
+        exercise(RwLockMetrics::new());
+        exercise(DashMapMetrics::new());
+    }
+}